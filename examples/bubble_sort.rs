@@ -1,16 +1,71 @@
-/// Bubble sort implementation in Rust
-fn bubble_sort(arr: &mut [i32]) {
+//! Bubble sort implementation in Rust.
+
+/// A pluggable sorting algorithm. Implementors sort a slice in place,
+/// letting callers write against the trait and swap algorithms without
+/// changing call sites.
+trait Sorter {
+    fn sort<T: Ord>(&self, slice: &mut [T]);
+}
+
+/// The classic bubble sort: repeatedly steps through the slice, swapping
+/// adjacent elements that are out of order, until a full pass makes no
+/// swaps.
+struct Bubble;
+
+impl Sorter for Bubble {
+    fn sort<T: Ord>(&self, arr: &mut [T]) {
+        bubble_sort_by(arr, |a, b| a.cmp(b));
+    }
+}
+
+/// Sorts `arr` in place using a caller-supplied comparator, which allows
+/// sorting in descending order, by a struct field, or sorting types that
+/// don't implement `Ord` at all.
+///
+/// This is a stable sort: it only swaps adjacent elements that compare as
+/// strictly greater-than, so elements that compare as equal never cross
+/// each other and keep their original relative order.
+fn bubble_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
     let n = arr.len();
     for i in 0..n {
+        let mut swapped = false;
         for j in 0..n - i - 1 {
-            if arr[j] > arr[j + 1] {
+            if compare(&arr[j], &arr[j + 1]) == std::cmp::Ordering::Greater {
                 // Swap elements
                 arr.swap(j, j + 1);
+                swapped = true;
             }
         }
+        if !swapped {
+            break;
+        }
     }
 }
 
+/// Thin wrapper kept for backward compatibility with callers that sorted
+/// directly via `bubble_sort` before the `Sorter` trait was introduced.
+fn bubble_sort<T: Ord>(arr: &mut [T]) {
+    Bubble.sort(arr);
+}
+
+/// Sorts `arr` in place by a derived key, e.g. sorting a slice of structs
+/// by one of their fields without writing out a full comparator.
+fn bubble_sort_by_key<T, K: Ord, F>(arr: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    bubble_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+#[derive(Debug, PartialEq)]
+struct Person {
+    name: &'static str,
+    age: u32,
+}
+
 fn main() {
     let mut numbers = vec![64, 34, 25, 12, 22, 11, 90];
     println!("Original array: {:?}", numbers);
@@ -18,6 +73,14 @@ fn main() {
     bubble_sort(&mut numbers);
 
     println!("Sorted array: {:?}", numbers);
+
+    let mut people = vec![
+        Person { name: "Carol", age: 35 },
+        Person { name: "Alice", age: 30 },
+        Person { name: "Bob", age: 25 },
+    ];
+    bubble_sort_by_key(&mut people, |p| p.age);
+    println!("People sorted by age: {:?}", people);
 }
 
 #[cfg(test)]
@@ -30,4 +93,140 @@ mod tests {
         bubble_sort(&mut arr);
         assert_eq!(arr, vec![1, 2, 5, 8, 9]);
     }
+
+    #[test]
+    fn test_bubble_sort_strings() {
+        let mut arr = vec![
+            String::from("banana"),
+            String::from("apple"),
+            String::from("cherry"),
+        ];
+        bubble_sort(&mut arr);
+        assert_eq!(
+            arr,
+            vec![
+                String::from("apple"),
+                String::from("banana"),
+                String::from("cherry"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bubble_sort_descending_input() {
+        let mut arr = vec![9, 7, 5, 3, 1];
+        bubble_sort(&mut arr);
+        assert_eq!(arr, vec![1, 3, 5, 7, 9]);
+    }
+
+    // `Sorter::sort` is generic over `T`, so the trait isn't object-safe and
+    // can't be used as `&dyn Sorter`. Callers drive it through the generic
+    // dispatch path instead, e.g. taking `&impl Sorter` as shown here.
+    fn sort_with(sorter: &impl Sorter, arr: &mut [i32]) {
+        sorter.sort(arr);
+    }
+
+    #[test]
+    fn test_sorter_trait_generic_dispatch() {
+        let mut arr = vec![5, 2, 8, 1, 9];
+        sort_with(&Bubble, &mut arr);
+        assert_eq!(arr, vec![1, 2, 5, 8, 9]);
+    }
+
+    #[test]
+    fn test_bubble_sort_already_sorted() {
+        let mut arr = vec![1, 2, 3, 4, 5];
+        bubble_sort(&mut arr);
+        assert_eq!(arr, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_bubble_sort_by_reverse_comparator() {
+        let mut arr = vec![5, 2, 8, 1, 9];
+        bubble_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![9, 8, 5, 2, 1]);
+    }
+
+    #[test]
+    fn test_bubble_sort_by_tuple_second_field() {
+        let mut arr = vec![(1, 'c'), (2, 'a'), (3, 'b')];
+        bubble_sort_by(&mut arr, |a, b| a.1.cmp(&b.1));
+        assert_eq!(arr, vec![(2, 'a'), (3, 'b'), (1, 'c')]);
+    }
+
+    #[test]
+    fn test_bubble_sort_by_key_sorts_structs_by_field() {
+        let mut people = vec![
+            Person { name: "Carol", age: 35 },
+            Person { name: "Alice", age: 30 },
+            Person { name: "Bob", age: 25 },
+        ];
+        bubble_sort_by_key(&mut people, |p| p.age);
+        assert_eq!(
+            people,
+            vec![
+                Person { name: "Bob", age: 25 },
+                Person { name: "Alice", age: 30 },
+                Person { name: "Carol", age: 35 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bubble_sort_by_key_is_stable() {
+        // Pairs sharing a first element must keep their original relative
+        // order once sorted by that first element alone.
+        let mut arr = vec![(1, 'a'), (2, 'a'), (1, 'b'), (2, 'b'), (1, 'c')];
+        bubble_sort_by_key(&mut arr, |&(n, _)| n);
+        assert_eq!(
+            arr,
+            vec![(1, 'a'), (1, 'b'), (1, 'c'), (2, 'a'), (2, 'b')]
+        );
+    }
+
+    #[test]
+    fn test_bubble_sort_early_exit_pass_count() {
+        use std::cell::Cell;
+        use std::cmp::Ordering;
+
+        // Wraps an i32 and counts every comparison it takes part in, so we
+        // can confirm the early-exit only lets a single pass run over
+        // already-sorted input instead of the full n-1 passes.
+        struct CountingItem<'a> {
+            value: i32,
+            comparisons: &'a Cell<u32>,
+        }
+
+        impl PartialEq for CountingItem<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
+            }
+        }
+        impl Eq for CountingItem<'_> {}
+        impl PartialOrd for CountingItem<'_> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for CountingItem<'_> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.comparisons.set(self.comparisons.get() + 1);
+                self.value.cmp(&other.value)
+            }
+        }
+
+        let comparisons = Cell::new(0);
+        let mut arr: Vec<CountingItem> = (1..=5)
+            .map(|value| CountingItem {
+                value,
+                comparisons: &comparisons,
+            })
+            .collect();
+
+        bubble_sort(&mut arr);
+
+        // A single pass over 5 already-sorted elements makes 4 comparisons;
+        // without the early exit it would make 4 + 3 + 2 + 1 = 10.
+        assert_eq!(comparisons.get(), 4);
+    }
 }